@@ -0,0 +1,127 @@
+//! Functions and types relating to the game's context, and the options used to create it.
+
+use crate::window::{MonitorSelection, PresentMode};
+use crate::{Context, Result};
+
+/// Builds a new [`Context`](struct.Context.html), with custom configuration.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tetra::ContextBuilder;
+///
+/// fn main() -> tetra::Result {
+///     ContextBuilder::new("Example", 1280, 720)
+///         .resizable(true)
+///         .quit_on_escape(true)
+///         .build()?
+///         .run(|ctx| Ok(todo!()))
+/// }
+/// ```
+pub struct ContextBuilder {
+    pub(crate) title: String,
+    pub(crate) window_width: i32,
+    pub(crate) window_height: i32,
+    pub(crate) present_mode: PresentMode,
+    pub(crate) resizable: bool,
+    pub(crate) quit_on_escape: bool,
+    pub(crate) minimum_size: Option<(i32, i32)>,
+    pub(crate) maximum_size: Option<(i32, i32)>,
+    pub(crate) fullscreen: bool,
+    pub(crate) monitor: MonitorSelection,
+}
+
+impl ContextBuilder {
+    /// Creates a new `ContextBuilder`, with default settings.
+    pub fn new<S>(title: S, window_width: i32, window_height: i32) -> ContextBuilder
+    where
+        S: Into<String>,
+    {
+        ContextBuilder {
+            title: title.into(),
+            window_width,
+            window_height,
+            present_mode: PresentMode::Fifo,
+            resizable: false,
+            quit_on_escape: false,
+            minimum_size: None,
+            maximum_size: None,
+            fullscreen: false,
+            monitor: MonitorSelection::Primary,
+        }
+    }
+
+    /// Sets whether or not the window should be resizable.
+    ///
+    /// Defaults to `false`.
+    pub fn resizable(&mut self, resizable: bool) -> &mut ContextBuilder {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Sets whether or not the game should close when the Escape key is pressed.
+    ///
+    /// Defaults to `false`.
+    pub fn quit_on_escape(&mut self, quit_on_escape: bool) -> &mut ContextBuilder {
+        self.quit_on_escape = quit_on_escape;
+        self
+    }
+
+    /// Sets the present mode that should be used when the context is created.
+    ///
+    /// See [`window::set_present_mode`](crate::window::set_present_mode) for details on
+    /// what each mode does.
+    ///
+    /// Defaults to `PresentMode::Fifo`.
+    pub fn present_mode(&mut self, present_mode: PresentMode) -> &mut ContextBuilder {
+        self.present_mode = present_mode;
+        self
+    }
+
+    /// Sets the minimum allowed size for the window.
+    ///
+    /// Defaults to `None` (no minimum).
+    pub fn minimum_size(&mut self, width: i32, height: i32) -> &mut ContextBuilder {
+        self.minimum_size = Some((width, height));
+        self
+    }
+
+    /// Sets the maximum allowed size for the window.
+    ///
+    /// Defaults to `None` (no maximum).
+    pub fn maximum_size(&mut self, width: i32, height: i32) -> &mut ContextBuilder {
+        self.maximum_size = Some((width, height));
+        self
+    }
+
+    /// Sets whether or not the window should be in (borderless) fullscreen mode when the
+    /// context is created.
+    ///
+    /// Defaults to `false`.
+    pub fn fullscreen(&mut self, fullscreen: bool) -> &mut ContextBuilder {
+        self.fullscreen = fullscreen;
+        self
+    }
+
+    /// Sets which monitor the window should be opened on - or, if `fullscreen` is also
+    /// set, which monitor it should go fullscreen on.
+    ///
+    /// `MonitorSelection::Current` has no window to refer to yet at this point, so it is
+    /// treated as `MonitorSelection::Primary` instead.
+    ///
+    /// Defaults to `MonitorSelection::Primary`.
+    pub fn monitor(&mut self, monitor: MonitorSelection) -> &mut ContextBuilder {
+        self.monitor = monitor;
+        self
+    }
+
+    /// Builds the context.
+    ///
+    /// # Errors
+    ///
+    /// * `TetraError::PlatformError` will be returned if the game's window could not be
+    /// created.
+    pub fn build(&mut self) -> Result<Context> {
+        Context::new(self)
+    }
+}
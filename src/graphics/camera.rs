@@ -0,0 +1,165 @@
+use crate::graphics::{self, Canvas};
+use crate::math::{Mat4, Vec2};
+use crate::{window, Context, Result};
+
+/// Where a [`Camera`](struct.Camera.html) renders to.
+///
+/// By default, a camera targets the window - use
+/// [`Camera::with_render_target`](struct.Camera.html#method.with_render_target) to point
+/// it at a [`Canvas`](struct.Canvas.html) instead, so that it can be composed with other
+/// cameras (for a minimap, split-screen view, or post-processing pass, for example).
+#[derive(Debug, Clone, PartialEq)]
+pub enum RenderTarget {
+    /// Render to the window.
+    Window,
+
+    /// Render to the given canvas.
+    Canvas(Canvas),
+}
+
+/// A camera that can be used to transform the player's view.
+///
+/// This is achieved by modifying the view's transform matrix, and then rendering anything
+/// that should be affected by the camera using that matrix.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use tetra::graphics::Camera;
+/// # use tetra::Context;
+/// #
+/// fn create_camera(ctx: &mut Context) -> Camera {
+///     Camera::with_window_size(ctx)
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Camera {
+    /// The position of the camera.
+    pub position: Vec2<f32>,
+
+    /// The rotation of the camera, in radians.
+    pub rotation: f32,
+
+    /// The zoom level of the camera. Defaults to 1.0.
+    pub zoom: f32,
+
+    viewport_width: f32,
+    viewport_height: f32,
+    target: RenderTarget,
+    matrix: Mat4<f32>,
+}
+
+impl Camera {
+    /// Creates a new camera, targeting the window, with the given viewport size.
+    pub fn new(viewport_width: f32, viewport_height: f32) -> Camera {
+        Camera {
+            position: Vec2::new(viewport_width / 2.0, viewport_height / 2.0),
+            rotation: 0.0,
+            zoom: 1.0,
+            viewport_width,
+            viewport_height,
+            target: RenderTarget::Window,
+            matrix: Mat4::identity(),
+        }
+    }
+
+    /// Creates a new camera, targeting the window, with the viewport size set to the
+    /// current size of the window.
+    pub fn with_window_size(ctx: &Context) -> Camera {
+        let (width, height) = window::get_size(ctx);
+        Camera::new(width as f32, height as f32)
+    }
+
+    /// Creates a new camera that renders to the given target.
+    ///
+    /// If the target is a [`Canvas`](struct.Canvas.html), the viewport size is derived from
+    /// the canvas' size. If it is the window, the viewport size is derived from the current
+    /// size of the window.
+    pub fn with_render_target(ctx: &Context, target: RenderTarget) -> Camera {
+        let (width, height) = match &target {
+            RenderTarget::Window => window::get_size(ctx),
+            RenderTarget::Canvas(canvas) => canvas.size(),
+        };
+
+        let mut camera = Camera::new(width as f32, height as f32);
+        camera.target = target;
+        camera
+    }
+
+    /// Returns the render target that this camera draws to.
+    pub fn render_target(&self) -> &RenderTarget {
+        &self.target
+    }
+
+    /// Sets the size of the camera's viewport.
+    ///
+    /// If this camera's render target is a [`Canvas`](struct.Canvas.html), the viewport
+    /// size is derived from the canvas' size instead of the given `width`/`height` - this
+    /// keeps the camera correct if it is re-sized in response to a window
+    /// `Event::Resized`, which does not reflect the size of the canvas.
+    pub fn set_viewport_size(&mut self, width: f32, height: f32) {
+        let (width, height) = match &self.target {
+            RenderTarget::Window => (width, height),
+            RenderTarget::Canvas(canvas) => {
+                let (w, h) = canvas.size();
+                (w as f32, h as f32)
+            }
+        };
+
+        self.viewport_width = width;
+        self.viewport_height = height;
+    }
+
+    /// Recalculates the camera's transform matrix, based on its current position, rotation
+    /// and zoom level.
+    ///
+    /// This needs to be called after changing the position/rotation/zoom of the camera, for
+    /// the changes to take effect.
+    pub fn update(&mut self) {
+        self.matrix = Mat4::<f32>::translation_2d(Vec2::new(
+            (self.viewport_width / 2.0).floor(),
+            (self.viewport_height / 2.0).floor(),
+        )) * Mat4::rotation_z(self.rotation)
+            * Mat4::scaling_3d(Vec2::new(self.zoom, self.zoom).with_z(1.0))
+            * Mat4::translation_2d(-self.position);
+    }
+
+    /// Converts the camera's position/rotation/zoom into a transformation matrix.
+    pub fn as_matrix(&self) -> Mat4<f32> {
+        self.matrix
+    }
+}
+
+/// Binds a camera's render target, applies its transform matrix, runs the given closure,
+/// and then restores whatever was bound before.
+///
+/// This lets you point several cameras at different [`Canvas`](struct.Canvas.html)es (for a
+/// minimap, split-screen view, or post-processing pass, for example) without manually
+/// juggling [`set_canvas`](fn.set_canvas.html)/[`reset_canvas`](fn.reset_canvas.html) calls
+/// and transform matrices yourself - including calling `draw_scene` again from within the
+/// closure, for a camera targeting a different canvas.
+pub fn draw_scene<F>(ctx: &mut Context, camera: &Camera, draw: F) -> Result
+where
+    F: FnOnce(&mut Context) -> Result,
+{
+    let previous_matrix = graphics::get_transform_matrix(ctx);
+    let previous_canvas = graphics::get_canvas(ctx).cloned();
+
+    match camera.render_target() {
+        RenderTarget::Window => graphics::reset_canvas(ctx),
+        RenderTarget::Canvas(canvas) => graphics::set_canvas(ctx, canvas),
+    }
+
+    graphics::set_transform_matrix(ctx, camera.as_matrix());
+
+    let result = draw(ctx);
+
+    graphics::set_transform_matrix(ctx, previous_matrix);
+
+    match previous_canvas {
+        Some(canvas) => graphics::set_canvas(ctx, &canvas),
+        None => graphics::reset_canvas(ctx),
+    }
+
+    result
+}
@@ -68,23 +68,123 @@ pub fn set_size(ctx: &mut Context, width: i32, height: i32) -> Result {
     ctx.window.set_window_size(width, height)
 }
 
-/// Sets whether the window should be vsynced.
+/// Gets the minimum allowed size of the window, if one has been set.
+pub fn get_minimum_size(ctx: &Context) -> Option<(i32, i32)> {
+    ctx.window.get_minimum_size()
+}
+
+/// Sets the minimum allowed size of the window.
+///
+/// If the window is currently smaller than the given size, it will be resized to fit -
+/// this will trigger an `Event::Resized` event.
+///
+/// # Errors
+///
+/// * `TetraError::FailedToChangeDisplayMode` will be returned if the game was unable to
+/// change the window size.
+pub fn set_minimum_size(ctx: &mut Context, width: i32, height: i32) -> Result {
+    ctx.window.set_minimum_size(width, height)
+}
+
+/// Gets the maximum allowed size of the window, if one has been set.
+pub fn get_maximum_size(ctx: &Context) -> Option<(i32, i32)> {
+    ctx.window.get_maximum_size()
+}
+
+/// Sets the maximum allowed size of the window.
+///
+/// If the window is currently larger than the given size, it will be resized to fit -
+/// this will trigger an `Event::Resized` event.
 ///
 /// # Errors
 ///
 /// * `TetraError::FailedToChangeDisplayMode` will be returned if the game was unable to
-/// change vsync mode.
+/// change the window size.
+pub fn set_maximum_size(ctx: &mut Context, width: i32, height: i32) -> Result {
+    ctx.window.set_maximum_size(width, height)
+}
+
+/// The strategy used to present rendered frames to the screen.
+///
+/// This controls how (and whether) frame presentation is synced to the display's
+/// refresh rate, which affects both screen tearing and input latency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PresentMode {
+    /// Presentation is synced to the display's refresh rate, capping the framerate and
+    /// preventing tearing. This is the traditional behaviour of vsync.
+    Fifo,
+
+    /// Frames are presented as soon as they are rendered, with no synchronization. This
+    /// gives the lowest possible input latency, but can result in screen tearing.
+    Immediate,
+
+    /// Frames are presented as soon as they are rendered, but are triple-buffered so that
+    /// tearing cannot occur. This gives latency similar to `Immediate`, without the visual
+    /// artifacts.
+    Mailbox,
+
+    /// Behaves like `Mailbox` if it is supported, falling back to `Fifo` otherwise.
+    AutoVsync,
+
+    /// Behaves like `Immediate` if it is supported, falling back to `Fifo` otherwise.
+    AutoNoVsync,
+}
+
+/// Sets the present mode that should be used to display rendered frames.
+///
+/// # Errors
+///
+/// * `TetraError::FailedToChangeDisplayMode` will be returned if the game explicitly
+/// requested `PresentMode::Immediate` or `PresentMode::Mailbox`, and the platform was
+/// unable to honor it. The `Auto*` modes will never return this error, as they fall back
+/// to `Fifo` instead.
+pub fn set_present_mode(ctx: &mut Context, present_mode: PresentMode) -> Result {
+    ctx.window.set_present_mode(present_mode)
+}
+
+/// Returns the present mode currently being used to display rendered frames.
+pub fn present_mode(ctx: &Context) -> PresentMode {
+    ctx.window.present_mode()
+}
+
+/// Sets whether the window should be vsynced.
+///
+/// This is a thin wrapper around [`set_present_mode`](fn.set_present_mode.html) -
+/// `true` maps to `PresentMode::AutoVsync`, and `false` maps to `PresentMode::AutoNoVsync`.
+///
+/// # Errors
+///
+/// This function will not error, as both of the present modes it maps to fall back to
+/// `PresentMode::Fifo` if they are unsupported.
 pub fn set_vsync(ctx: &mut Context, vsync: bool) -> Result {
-    ctx.window.set_vsync(vsync)
+    set_present_mode(
+        ctx,
+        if vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        },
+    )
 }
 
 /// Returns whethere or not vsync is enabled.
+///
+/// This is a thin wrapper around [`present_mode`](fn.present_mode.html) - it returns
+/// `false` if the current present mode is `PresentMode::Immediate` or
+/// `PresentMode::AutoNoVsync`, and `true` otherwise.
 pub fn is_vsync_enabled(ctx: &Context) -> bool {
-    ctx.window.is_vsync_enabled()
+    !matches!(
+        present_mode(ctx),
+        PresentMode::Immediate | PresentMode::AutoNoVsync
+    )
 }
 
 /// Sets whether the window should be in fullscreen mode.
 ///
+/// This puts the window into borderless (desktop) fullscreen, at its current resolution. If
+/// you need control over the resolution and refresh rate, use
+/// [`set_exclusive_fullscreen`](fn.set_exclusive_fullscreen.html) instead.
+///
 /// # Errors
 ///
 /// * `TetraError::FailedToChangeDisplayMode` will be returned if the game was unable to
@@ -98,6 +198,44 @@ pub fn is_fullscreen(ctx: &Context) -> bool {
     ctx.window.is_fullscreen()
 }
 
+/// A video mode supported by a monitor, for use with exclusive fullscreen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VideoMode {
+    /// The resolution of the video mode, in pixels.
+    pub resolution: (i32, i32),
+
+    /// The refresh rate of the video mode, in hertz.
+    pub refresh_rate: u16,
+
+    /// The number of bits used to represent the color of a single pixel.
+    pub bit_depth: u16,
+
+    /// The index of the monitor that this video mode belongs to.
+    pub monitor_index: i32,
+}
+
+/// Gets the video modes supported by a monitor connected to the device.
+///
+/// # Errors
+///
+/// * `TetraError::PlatformError` will be returned if the monitor state was inaccessible.
+pub fn get_fullscreen_modes(ctx: &Context, monitor_index: i32) -> Result<Vec<VideoMode>> {
+    ctx.window.get_fullscreen_modes(monitor_index)
+}
+
+/// Switches the window into exclusive fullscreen mode, using the given video mode.
+///
+/// Unlike [`set_fullscreen`](fn.set_fullscreen.html), this takes an exclusive lock on the
+/// display at the chosen resolution and refresh rate, which can reduce input latency.
+///
+/// # Errors
+///
+/// * `TetraError::FailedToChangeDisplayMode` will be returned if the game was unable to
+/// switch to the requested video mode.
+pub fn set_exclusive_fullscreen(ctx: &mut Context, video_mode: &VideoMode) -> Result {
+    ctx.window.set_exclusive_fullscreen(video_mode)
+}
+
 /// Sets whether or not the mouse cursor should be visible.
 ///
 /// # Errors
@@ -112,93 +250,217 @@ pub fn is_mouse_visible(ctx: &Context) -> bool {
     ctx.window.is_mouse_visible()
 }
 
-/// Get the number of monitors connected to the device.
+/// A system cursor icon, for use with [`set_cursor_icon`](fn.set_cursor_icon.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CursorIcon {
+    /// The platform's default cursor.
+    Arrow,
+
+    /// A pointing hand, typically used to indicate a clickable link or button.
+    Hand,
+
+    /// An I-beam, typically used to indicate selectable/editable text.
+    Text,
+
+    /// A crosshair, typically used for precise selection.
+    Crosshair,
+
+    /// A horizontal resize handle.
+    ResizeHorizontal,
+
+    /// A vertical resize handle.
+    ResizeVertical,
+
+    /// A cursor indicating that the current action is not allowed.
+    NotAllowed,
+}
+
+/// Sets the icon that should be displayed for the mouse cursor.
 ///
 /// # Errors
 ///
-/// * `TetraError::PlatformError` will be returned if the monitor state was inaccessible.
-pub fn get_monitor_count(ctx: &Context) -> Result<i32> {
-    ctx.window.get_monitor_count()
+/// * `TetraError::PlatformError` will be returned if the cursor state was inaccessible.
+pub fn set_cursor_icon(ctx: &mut Context, icon: CursorIcon) -> Result {
+    ctx.window.set_cursor_icon(icon)
 }
 
-/// Get the name of a monitor connected to the device.
+/// The kind of attention that a game should request from the player.
+///
+/// Used with [`request_attention`](fn.request_attention.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UserAttentionType {
+    /// Demands the player's attention urgently - for example, by bouncing the dock icon
+    /// on macOS, or flashing the taskbar entry persistently on Windows.
+    Critical,
+
+    /// Requests the player's attention politely - for example, by flashing the taskbar
+    /// entry a single time.
+    Informational,
+}
+
+/// Requests the user's attention, for example by flashing or bouncing the window's icon
+/// in the taskbar/dock.
+///
+/// This is useful for letting the player know that something needs them while the game is
+/// running in the background.
 ///
 /// # Errors
 ///
-/// * `TetraError::PlatformError` will be returned if the monitor state was inaccessible.
-pub fn get_monitor_name(ctx: &Context, monitor_index: i32) -> Result<String> {
-    ctx.window.get_monitor_name(monitor_index)
+/// * `TetraError::PlatformError` will be returned if the platform was unable to honor the
+/// request.
+pub fn request_attention(ctx: &mut Context, attention_type: UserAttentionType) -> Result {
+    ctx.window.request_attention(attention_type)
 }
 
-/// Get the width of a monitor connected to the device.
+/// A monitor connected to the device.
+///
+/// Use [`get_monitors`](fn.get_monitors.html) to get the monitors currently available.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    pub(crate) name: String,
+    pub(crate) size: (i32, i32),
+    pub(crate) position: (i32, i32),
+    pub(crate) scale_factor: f32,
+}
+
+impl Monitor {
+    /// Returns the name of the monitor.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the size of the monitor, in pixels.
+    pub fn size(&self) -> (i32, i32) {
+        self.size
+    }
+
+    /// Returns the position of the monitor, in virtual-desktop coordinates.
+    pub fn position(&self) -> (i32, i32) {
+        self.position
+    }
+
+    /// Returns the ratio between the monitor's physical pixels and logical pixels.
+    ///
+    /// This is useful for sizing a [`Canvas`](crate::graphics::Canvas) so that it renders at
+    /// native resolution on HiDPI displays.
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+}
+
+/// Selects a monitor, for use with [`ContextBuilder`](crate::ContextBuilder) or
+/// [`set_fullscreen_on_monitor`](fn.set_fullscreen_on_monitor.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MonitorSelection {
+    /// The primary monitor, as reported by the OS.
+    Primary,
+
+    /// The monitor at the given index, as returned by
+    /// [`get_monitors`](fn.get_monitors.html).
+    Index(i32),
+
+    /// The monitor that the window is currently on.
+    ///
+    /// There is no window yet when this is used with
+    /// [`ContextBuilder`](crate::ContextBuilder), so it is treated as `Primary` in that
+    /// context instead.
+    Current,
+}
+
+/// Gets the monitors connected to the device.
 ///
 /// # Errors
 ///
 /// * `TetraError::PlatformError` will be returned if the monitor state was inaccessible.
-pub fn get_monitor_width(ctx: &Context, monitor_index: i32) -> Result<i32> {
-    get_monitor_size(ctx, monitor_index).map(|(w, _)| w)
+pub fn get_monitors(ctx: &Context) -> Result<Vec<Monitor>> {
+    ctx.window.get_monitors()
 }
 
-/// Get the height of a monitor connected to the device.
+/// Sets the position of the window, in virtual-desktop coordinates.
 ///
 /// # Errors
 ///
-/// * `TetraError::PlatformError` will be returned if the monitor state was inaccessible.
-pub fn get_monitor_height(ctx: &Context, monitor_index: i32) -> Result<i32> {
-    get_monitor_size(ctx, monitor_index).map(|(_, h)| h)
+/// * `TetraError::PlatformError` will be returned if the window state was inaccessible.
+pub fn set_position(ctx: &mut Context, x: i32, y: i32) -> Result {
+    ctx.window.set_window_position(x, y)
 }
 
-/// Get the size of a monitor connected to the device.
+/// Switches the window into (borderless) fullscreen mode on the given monitor.
+///
+/// Unlike [`set_fullscreen`](fn.set_fullscreen.html), this always turns fullscreen on - to
+/// turn it back off, call `set_fullscreen(ctx, false)`.
 ///
 /// # Errors
 ///
-/// * `TetraError::PlatformError` will be returned if the monitor state was inaccessible.
+/// * `TetraError::FailedToChangeDisplayMode` will be returned if the game was unable to
+/// enter fullscreen on the requested monitor.
+pub fn set_fullscreen_on_monitor(ctx: &mut Context, monitor: MonitorSelection) -> Result {
+    ctx.window.set_fullscreen_on_monitor(monitor)
+}
+
+/// Get the number of monitors connected to the device.
+#[deprecated(note = "use `get_monitors` instead")]
+pub fn get_monitor_count(ctx: &Context) -> Result<i32> {
+    ctx.window.get_monitor_count()
+}
+
+/// Get the name of a monitor connected to the device.
+#[deprecated(note = "use `get_monitors` instead")]
+pub fn get_monitor_name(ctx: &Context, monitor_index: i32) -> Result<String> {
+    ctx.window.get_monitor_name(monitor_index)
+}
+
+/// Get the width of a monitor connected to the device.
+#[deprecated(note = "use `get_monitors` instead")]
+#[allow(deprecated)]
+pub fn get_monitor_width(ctx: &Context, monitor_index: i32) -> Result<i32> {
+    get_monitor_size(ctx, monitor_index).map(|(w, _)| w)
+}
+
+/// Get the height of a monitor connected to the device.
+#[deprecated(note = "use `get_monitors` instead")]
+#[allow(deprecated)]
+pub fn get_monitor_height(ctx: &Context, monitor_index: i32) -> Result<i32> {
+    get_monitor_size(ctx, monitor_index).map(|(_, h)| h)
+}
+
+/// Get the size of a monitor connected to the device.
+#[deprecated(note = "use `get_monitors` instead")]
 pub fn get_monitor_size(ctx: &Context, monitor_index: i32) -> Result<(i32, i32)> {
     ctx.window.get_monitor_size(monitor_index)
 }
 
 /// Get the index of the monitor that the window is currently on.
-///
-/// # Errors
-///
-/// * `TetraError::PlatformError` will be returned if the monitor state was inaccessible.
+#[deprecated(note = "use `get_monitors` instead")]
 pub fn get_current_monitor(ctx: &Context) -> Result<i32> {
     ctx.window.get_current_monitor()
 }
 
 /// Get the name of the monitor that the window is currently on.
-///
-/// # Errors
-///
-/// * `TetraError::PlatformError` will be returned if the monitor state was inaccessible.
+#[deprecated(note = "use `get_monitors` instead")]
+#[allow(deprecated)]
 pub fn get_current_monitor_name(ctx: &Context) -> Result<String> {
     let monitor_index = ctx.window.get_current_monitor()?;
     ctx.window.get_monitor_name(monitor_index)
 }
 
 /// Get the width of the monitor that the window is currently on.
-///
-/// # Errors
-///
-/// * `TetraError::PlatformError` will be returned if the monitor state was inaccessible.
+#[deprecated(note = "use `get_monitors` instead")]
+#[allow(deprecated)]
 pub fn get_current_monitor_width(ctx: &Context) -> Result<i32> {
     get_current_monitor_size(ctx).map(|(w, _)| w)
 }
 
 /// Get the height of the monitor that the window is currently on.
-///
-/// # Errors
-///
-/// * `TetraError::PlatformError` will be returned if the monitor state was inaccessible.
+#[deprecated(note = "use `get_monitors` instead")]
+#[allow(deprecated)]
 pub fn get_current_monitor_height(ctx: &Context) -> Result<i32> {
     get_current_monitor_size(ctx).map(|(_, h)| h)
 }
 
 /// Get the size of the monitor that the window is currently on.
-///
-/// # Errors
-///
-/// * `TetraError::PlatformError` will be returned if the monitor state was inaccessible.
+#[deprecated(note = "use `get_monitors` instead")]
+#[allow(deprecated)]
 pub fn get_current_monitor_size(ctx: &Context) -> Result<(i32, i32)> {
     let monitor_index = ctx.window.get_current_monitor()?;
     ctx.window.get_monitor_size(monitor_index)